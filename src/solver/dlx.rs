@@ -0,0 +1,289 @@
+//! Dancing Links (Algorithm X) exact-cover backend.
+//!
+//! The sudoku is modeled as an exact-cover problem over 324 constraint
+//! columns (81 "cell is filled", 81 "row has digit", 81 "column has digit",
+//! 81 "block has digit") and 729 candidate rows, one per (cell, digit)
+//! placement, each covering exactly its four constraints. Algorithm X is run
+//! over a doubly-linked toroidal matrix, always branching on the column with
+//! the fewest remaining rows (the "S" heuristic).
+//!
+//! This complements the step-by-step [`strategy`](crate::strategy) engine for
+//! cases where only a fast fill or a uniqueness check is needed.
+
+use crate::board::*;
+
+const COLUMNS: usize = 324;
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+	left: usize,
+	right: usize,
+	up: usize,
+	down: usize,
+	column: usize,
+}
+
+/// A (cell, digit) placement, identified by its row in the exact-cover matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Row(Cell, Digit);
+
+/// The doubly-linked toroidal matrix backing Algorithm X.
+struct Dlx {
+	nodes: Vec<Node>,
+	sizes: Vec<usize>,
+	row_of: Vec<Row>,
+}
+
+impl Dlx {
+	fn new(givens: &[Option<Digit>; 81]) -> Self {
+		// Node 0 is the root; nodes 1..=COLUMNS are column headers.
+		let mut nodes = Vec::with_capacity(COLUMNS + 1 + 81 * 9 * 4);
+		nodes.push(Node { left: COLUMNS, right: 1, up: ROOT, down: ROOT, column: ROOT });
+		for column in 1..=COLUMNS {
+			nodes.push(Node {
+				left: column - 1,
+				right: if column == COLUMNS { ROOT } else { column + 1 },
+				up: column,
+				down: column,
+				column,
+			});
+		}
+		nodes[ROOT].left = COLUMNS;
+		nodes[COLUMNS].right = ROOT;
+
+		let mut dlx = Dlx { nodes, sizes: vec![0; COLUMNS + 1], row_of: Vec::new() };
+
+		for cell in Cell::all() {
+			for digit in Digit::all() {
+				dlx.add_row(cell, digit);
+			}
+		}
+
+		for cell in Cell::all() {
+			if let Some(digit) = givens[cell.as_index()] {
+				dlx.cover_row_for(cell, digit);
+			}
+		}
+
+		dlx
+	}
+
+	fn columns_of(cell: Cell, digit: Digit) -> [usize; 4] {
+		let idx = cell.as_index();
+		let row = idx / 9;
+		let col = idx % 9;
+		let block = (row / 3) * 3 + col / 3;
+		let d = digit.as_index();
+		[
+			1 + idx,
+			1 + 81 + row * 9 + d,
+			1 + 162 + col * 9 + d,
+			1 + 243 + block * 9 + d,
+		]
+	}
+
+	fn add_row(&mut self, cell: Cell, digit: Digit) {
+		let mut first = None;
+		let mut prev = None;
+		for &column in &Self::columns_of(cell, digit) {
+			let node_index = self.nodes.len();
+			let up = self.nodes[column].up;
+			self.nodes.push(Node { left: node_index, right: node_index, up, down: column, column });
+			self.nodes[up].down = node_index;
+			self.nodes[column].up = node_index;
+			self.sizes[column] += 1;
+			self.row_of.push(Row(cell, digit));
+
+			if let Some(prev) = prev {
+				self.nodes[prev].right = node_index;
+				self.nodes[node_index].left = prev;
+			}
+			prev = Some(node_index);
+			first.get_or_insert(node_index);
+		}
+		let first = first.unwrap();
+		let last = prev.unwrap();
+		self.nodes[first].left = last;
+		self.nodes[last].right = first;
+	}
+
+	fn cover(&mut self, column: usize) {
+		let col_node = &self.nodes[column];
+		let (left, right) = (col_node.left, col_node.right);
+		self.nodes[right].left = left;
+		self.nodes[left].right = right;
+
+		let mut row = self.nodes[column].down;
+		while row != column {
+			let mut j = self.nodes[row].right;
+			while j != row {
+				let node = self.nodes[j];
+				self.nodes[node.up].down = node.down;
+				self.nodes[node.down].up = node.up;
+				self.sizes[node.column] -= 1;
+				j = self.nodes[j].right;
+			}
+			row = self.nodes[row].down;
+		}
+	}
+
+	fn uncover(&mut self, column: usize) {
+		let mut row = self.nodes[column].up;
+		while row != column {
+			let mut j = self.nodes[row].left;
+			while j != row {
+				let node = self.nodes[j];
+				self.sizes[node.column] += 1;
+				self.nodes[node.up].down = j;
+				self.nodes[node.down].up = j;
+				j = self.nodes[j].left;
+			}
+			row = self.nodes[row].up;
+		}
+
+		let col_node = &self.nodes[column];
+		let (left, right) = (col_node.left, col_node.right);
+		self.nodes[right].left = column;
+		self.nodes[left].right = column;
+	}
+
+	/// Covers the row corresponding to a given placement, pre-selecting it as
+	/// part of the initial (partial) solution.
+	fn cover_row_for(&mut self, cell: Cell, digit: Digit) {
+		for &column in &Self::columns_of(cell, digit) {
+			self.cover(column);
+		}
+	}
+
+	fn choose_column(&self) -> Option<usize> {
+		let mut best = None;
+		let mut column = self.nodes[ROOT].right;
+		while column != ROOT {
+			let size = self.sizes[column];
+			if best.map_or(true, |(_, best_size)| size < best_size) {
+				best = Some((column, size));
+			}
+			column = self.nodes[column].right;
+		}
+		best.map(|(column, _)| column)
+	}
+
+	/// Runs Algorithm X, calling `on_solution` for every exact cover found and
+	/// stopping early once it returns `false`.
+	fn search(&mut self, partial: &mut Vec<Row>, on_solution: &mut impl FnMut(&[Row]) -> bool) -> bool {
+		let Some(column) = self.choose_column() else {
+			return on_solution(partial);
+		};
+		if self.sizes[column] == 0 {
+			return true;
+		}
+
+		self.cover(column);
+		let mut row = self.nodes[column].down;
+		while row != column {
+			partial.push(self.row_of[row - COLUMNS - 1]);
+			let mut j = self.nodes[row].right;
+			while j != row {
+				self.cover(self.nodes[j].column);
+				j = self.nodes[j].right;
+			}
+
+			if !self.search(partial, on_solution) {
+				self.uncover_row(row);
+				self.uncover(column);
+				partial.pop();
+				return false;
+			}
+
+			let mut j = self.nodes[row].left;
+			while j != row {
+				self.uncover(self.nodes[j].column);
+				j = self.nodes[j].left;
+			}
+			partial.pop();
+			row = self.nodes[row].down;
+		}
+		self.uncover(column);
+		true
+	}
+
+	fn uncover_row(&mut self, row: usize) {
+		let mut j = self.nodes[row].left;
+		while j != row {
+			self.uncover(self.nodes[j].column);
+			j = self.nodes[j].left;
+		}
+	}
+}
+
+fn rows_to_grid(givens: &[Option<Digit>; 81], rows: &[Row]) -> [Option<Digit>; 81] {
+	let mut grid = *givens;
+	for &Row(cell, digit) in rows {
+		grid[cell.as_index()] = Some(digit);
+	}
+	grid
+}
+
+/// Finds the first full solution consistent with `givens`, or `None` if the
+/// puzzle has no solution.
+pub fn find_first_solution(givens: &[Option<Digit>; 81]) -> Option<[Option<Digit>; 81]> {
+	let mut dlx = Dlx::new(givens);
+	let mut solution = None;
+	let mut partial = Vec::new();
+	dlx.search(&mut partial, &mut |rows| {
+		solution = Some(rows_to_grid(givens, rows));
+		false
+	});
+	solution
+}
+
+/// Counts up to two solutions consistent with `givens`. A sudoku is uniquely
+/// solvable iff this returns `1`.
+pub fn count_solutions_up_to_two(givens: &[Option<Digit>; 81]) -> usize {
+	let mut dlx = Dlx::new(givens);
+	let mut count = 0;
+	let mut partial = Vec::new();
+	dlx.search(&mut partial, &mut |_rows| {
+		count += 1;
+		count < 2
+	});
+	count
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Parses an 81-character row-major puzzle string ('.'/'0' for blanks,
+	/// '1'-'9' for givens) into a candidate grid.
+	fn parse(line: &str) -> [Option<Digit>; 81] {
+		let mut grid = [None; 81];
+		for (i, ch) in line.chars().enumerate() {
+			if let Some(d) = ch.to_digit(10) {
+				if d > 0 {
+					grid[i] = Some(Digit::new((d - 1) as u8));
+				}
+			}
+		}
+		grid
+	}
+
+	#[test]
+	fn finds_the_unique_solution() {
+		let givens = parse(
+			"4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......",
+		);
+		let expected = parse(
+			"417369825632158947958724316825437169791586432346912758289643571573291684164875293",
+		);
+		assert_eq!(find_first_solution(&givens), Some(expected));
+		assert_eq!(count_solutions_up_to_two(&givens), 1);
+	}
+
+	#[test]
+	fn detects_a_non_unique_puzzle() {
+		let empty = [None; 81];
+		assert_eq!(count_solutions_up_to_two(&empty), 2);
+	}
+}
@@ -0,0 +1,8 @@
+//! Alternative, non-human-style ways of solving a sudoku: probability-based
+//! guessing and a Dancing Links exact-cover backend.
+
+mod probability;
+mod dlx;
+
+pub use self::probability::{CandidateProbabilities, ProbabilityError, estimate_probabilities};
+pub use self::dlx::{find_first_solution, count_solutions_up_to_two};
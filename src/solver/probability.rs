@@ -0,0 +1,243 @@
+//! Estimates, for every empty cell, the probability that each remaining
+//! candidate digit is the correct one, by sampling full solutions consistent
+//! with the current candidate sets.
+//!
+//! This is for puzzles where the human-style [`Deductions`](crate::strategy::deduction::Deductions)
+//! engine has stalled and a guess is unavoidable: instead of guessing blindly,
+//! rank candidates by how often they appear across many completions.
+
+use crate::board::*;
+use crate::bitset::Set;
+
+/// Default bound on the number of completions enumerated before the
+/// probabilities are computed from whatever was found so far.
+pub const DEFAULT_MAX_SOLUTIONS: usize = 10_000;
+
+/// The state is contradictory: no completion exists, so no probabilities
+/// can be derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProbabilityError;
+
+/// Per-cell, per-digit probabilities derived from sampled completions.
+#[derive(Debug, Clone)]
+pub struct CandidateProbabilities {
+	// probability[cell][digit - 1]
+	probability: Box<[[f64; 9]; 81]>,
+	solutions_sampled: usize,
+}
+
+impl CandidateProbabilities {
+	/// The probability that `digit` belongs in `cell`, estimated from the
+	/// sampled completions.
+	pub fn probability(&self, cell: Cell, digit: Digit) -> f64 {
+		self.probability[cell.as_index()][digit.as_index()]
+	}
+
+	/// The number of completions the probabilities were derived from. If this
+	/// is `1`, the probabilities have collapsed to 0/1 and the puzzle is
+	/// effectively solved.
+	pub fn solutions_sampled(&self) -> usize {
+		self.solutions_sampled
+	}
+
+	/// The single most likely (cell, digit) guess among the cells that aren't
+	/// already solved, or `None` if every cell is already solved.
+	pub fn best_guess(&self, candidates: &[Set<Digit>; 81]) -> Option<(Cell, Digit)> {
+		Cell::all()
+			.filter(|&cell| candidates[cell.as_index()].len() > 1)
+			.flat_map(|cell| Digit::all().map(move |digit| (cell, digit)))
+			.max_by(|&(a_cell, a_digit), &(b_cell, b_digit)| {
+				self.probability(a_cell, a_digit)
+					.partial_cmp(&self.probability(b_cell, b_digit))
+					.unwrap()
+			})
+	}
+}
+
+/// Estimates candidate probabilities for `candidates`, sampling up to
+/// [`DEFAULT_MAX_SOLUTIONS`] completions.
+pub fn estimate_probabilities(
+	candidates: &[Set<Digit>; 81],
+) -> Result<CandidateProbabilities, ProbabilityError> {
+	estimate_probabilities_bounded(candidates, DEFAULT_MAX_SOLUTIONS)
+}
+
+/// Estimates candidate probabilities for `candidates`, sampling up to
+/// `max_solutions` completions via bounded depth-first enumeration.
+///
+/// If exactly one completion is found, the puzzle is effectively solved and
+/// the probabilities collapse to 0 or 1. If zero completions are found, the
+/// state is contradictory and [`ProbabilityError`] is returned instead of
+/// dividing by zero.
+pub fn estimate_probabilities_bounded(
+	candidates: &[Set<Digit>; 81],
+	max_solutions: usize,
+) -> Result<CandidateProbabilities, ProbabilityError> {
+	let mut counts = [[0u32; 9]; 81];
+	let mut solutions_found = 0;
+
+	let mut working = candidates.clone();
+	enumerate(&mut working, max_solutions, &mut solutions_found, &mut counts);
+
+	if solutions_found == 0 {
+		return Err(ProbabilityError);
+	}
+
+	let mut probability = Box::new([[0f64; 9]; 81]);
+	for cell in 0..81 {
+		for digit in 0..9 {
+			probability[cell][digit] = counts[cell][digit] as f64 / solutions_found as f64;
+		}
+	}
+
+	Ok(CandidateProbabilities { probability, solutions_sampled: solutions_found })
+}
+
+/// Depth-first enumeration of full solutions, stopping once `max_solutions`
+/// have been found. Always picks the empty cell with the fewest remaining
+/// candidates next (most-constrained-first), to keep dead branches short.
+fn enumerate(
+	candidates: &mut [Set<Digit>; 81],
+	max_solutions: usize,
+	solutions_found: &mut usize,
+	counts: &mut [[u32; 9]; 81],
+) {
+	if *solutions_found >= max_solutions {
+		return;
+	}
+
+	let mut next_cell = None;
+	let mut best_len = usize::MAX;
+	for cell in Cell::all() {
+		let len = candidates[cell.as_index()].len();
+		if len == 0 {
+			// Contradictory state: no completion down this branch, so it must
+			// not be counted as a solution.
+			return;
+		}
+		if len > 1 && len < best_len {
+			best_len = len;
+			next_cell = Some(cell);
+		}
+	}
+
+	let Some(cell) = next_cell else {
+		*solutions_found += 1;
+		for c in Cell::all() {
+			let digit = candidates[c.as_index()].into_iter().next().unwrap();
+			counts[c.as_index()][digit.as_index()] += 1;
+		}
+		return;
+	};
+
+	for digit in candidates[cell.as_index()] {
+		let mut next = candidates.clone();
+		next[cell.as_index()] = Set::from(digit);
+		if eliminate_peers(&mut next, cell, digit) {
+			enumerate(&mut next, max_solutions, solutions_found, counts);
+		}
+		if *solutions_found >= max_solutions {
+			return;
+		}
+	}
+}
+
+/// Removes `digit` from the candidates of every cell that sees `cell`, and in
+/// turn the remaining digit of every peer this leaves with a single candidate.
+/// Returns `false` if this empties some cell's candidate set, i.e. the
+/// placement is contradictory.
+fn eliminate_peers(candidates: &mut [Set<Digit>; 81], cell: Cell, digit: Digit) -> bool {
+	for peer in Cell::all().filter(|&peer| peer != cell && cell.sees(peer)) {
+		let peer_candidates = &mut candidates[peer.as_index()];
+		if peer_candidates.len() == 1 {
+			if peer_candidates.contains(digit) {
+				return false;
+			}
+			continue;
+		}
+		peer_candidates.remove(digit);
+		if peer_candidates.is_empty() {
+			return false;
+		}
+		if peer_candidates.len() == 1 {
+			let remaining = *peer_candidates;
+			let only = remaining.into_iter().next().unwrap();
+			if !eliminate_peers(candidates, peer, only) {
+				return false;
+			}
+		}
+	}
+	true
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The canonical 81-cell solution used in `solver::dlx`'s tests, as
+	/// already-solved (singleton) candidate sets.
+	const SOLVED: &str = "417369825632158947958724316825437169791586432346912758289643571573291684164875293";
+
+	fn singleton(digit_char: char) -> Set<Digit> {
+		let d = digit_char.to_digit(10).unwrap();
+		Set::from(Digit::new((d - 1) as u8))
+	}
+
+	#[test]
+	fn fully_solved_grid_collapses_to_certain_probabilities() {
+		let mut candidates = [Set::from(Digit::new(0)); 81];
+		for (i, ch) in SOLVED.chars().enumerate() {
+			candidates[i] = singleton(ch);
+		}
+
+		let probabilities = estimate_probabilities(&candidates).unwrap();
+		assert_eq!(probabilities.solutions_sampled(), 1);
+		for (i, ch) in SOLVED.chars().enumerate() {
+			let cell = Cell::new(i);
+			let given = singleton(ch).into_iter().next().unwrap();
+			for digit in Digit::all() {
+				let expected = if digit == given { 1.0 } else { 0.0 };
+				assert_eq!(probabilities.probability(cell, digit), expected);
+			}
+		}
+		assert!(probabilities.best_guess(&candidates).is_none());
+	}
+
+	#[test]
+	fn already_solved_peer_blocks_conflicting_placement() {
+		let blocked = Digit::new(0);
+		let open = Digit::new(1);
+		let filler = Digit::new(8);
+
+		let mut free_candidates = Set::from(blocked);
+		free_candidates.insert(open);
+
+		let mut candidates = [Set::from(filler); 81];
+		candidates[0] = Set::from(blocked); // r0c0: solved, shares row 0 with the free cell
+		candidates[1] = free_candidates; // r0c1: the only free cell
+		let free_cell = Cell::new(1);
+
+		let probabilities = estimate_probabilities(&candidates).unwrap();
+		assert_eq!(probabilities.solutions_sampled(), 1);
+		assert_eq!(probabilities.probability(free_cell, blocked), 0.0);
+		assert_eq!(probabilities.probability(free_cell, open), 1.0);
+		assert_eq!(probabilities.best_guess(&candidates), Some((free_cell, open)));
+	}
+
+	#[test]
+	fn contradictory_grid_returns_probability_error() {
+		let blocked_by_row = Digit::new(0);
+		let blocked_by_col = Digit::new(1);
+		let filler = Digit::new(8);
+
+		let mut free_candidates = Set::from(blocked_by_row);
+		free_candidates.insert(blocked_by_col);
+
+		let mut candidates = [Set::from(filler); 81];
+		candidates[0] = Set::from(blocked_by_row); // r0c0: shares row 0 with the free cell
+		candidates[1] = free_candidates; // r0c1: the only free cell, both options blocked
+		candidates[46] = Set::from(blocked_by_col); // r5c1: shares column 1 with the free cell
+
+		assert!(matches!(estimate_probabilities(&candidates), Err(ProbabilityError)));
+	}
+}
@@ -14,12 +14,18 @@ pub struct Deductions {
 	pub(crate) deductions: Vec<_Deduction>,
 	pub(crate) deduced_entries: Vec<Candidate>,
 	pub(crate) eliminated_entries: Vec<Candidate>,
+	/// Display/explanation metadata for chain-based strategies, e.g. the
+	/// [`SinglesChain`](Deduction::SinglesChain) candidates whose candidacy
+	/// was *not* disproven. Unlike `eliminated_entries`, these candidates are
+	/// still valid and must not be stripped from the board.
+	pub(crate) chain_entries: Vec<Candidate>,
 }
 
 /// Borrowing iterator over [`Deductions`]
 pub struct Iter<'a> {
 	deductions: std::slice::Iter<'a, _Deduction>,
-	eliminated_entries: &'a [Candidate]
+	eliminated_entries: &'a [Candidate],
+	chain_entries: &'a [Candidate],
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -27,7 +33,7 @@ impl<'a> Iterator for Iter<'a> {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		self.deductions.next()
-			.map(|deduction| deduction.clone().with_slices(self.eliminated_entries))
+			.map(|deduction| deduction.clone().with_slices(self.eliminated_entries, self.chain_entries))
 	}
 }
 
@@ -40,7 +46,7 @@ impl Deductions {
 	/// Return the `index`th Deduction, if it exists.
 	pub fn get(&self, index: usize) -> Option<Deduction<&[Candidate]>> {
 		self.deductions.get(index)
-			.map(|deduction| deduction.clone().with_slices(&self.eliminated_entries))
+			.map(|deduction| deduction.clone().with_slices(&self.eliminated_entries, &self.chain_entries))
 	}
 
 	/// Return an iterator over the deductions.
@@ -48,6 +54,71 @@ impl Deductions {
 		Iter {
 			deductions: self.deductions.iter(),
 			eliminated_entries: &self.eliminated_entries,
+			chain_entries: &self.chain_entries,
+		}
+	}
+
+	/// A numeric difficulty score derived from the strategies that were
+	/// required to produce these deductions.
+	///
+	/// The hardest strategy used dominates the score, but repeated uses of
+	/// hard strategies add extra weight on top, so two puzzles that both
+	/// require e.g. [`Swordfish`](Strategy::Swordfish) aren't necessarily
+	/// rated equally hard.
+	pub fn score(&self) -> u32 {
+		let costs: Vec<u32> = self.iter().map(|deduction| strategy_cost(deduction.strategy())).collect();
+		let dominant = costs.iter().copied().max().unwrap_or(0);
+		let total: u32 = costs.iter().sum();
+		dominant * 1000 + total.min(999)
+	}
+
+	/// A coarse difficulty rating derived from the hardest strategy that was
+	/// required to produce these deductions.
+	pub fn difficulty(&self) -> Difficulty {
+		let dominant = self.iter().map(|deduction| strategy_cost(deduction.strategy())).max().unwrap_or(0);
+		Difficulty::from_cost(dominant)
+	}
+}
+
+/// The base cost of a strategy, used by [`Deductions::score`] and
+/// [`Deductions::difficulty`]. Strategies are ordered roughly the way a human
+/// solver would reach for them: naked/hidden singles are nearly free, fish
+/// and chains are the most expensive.
+fn strategy_cost(strategy: Strategy) -> u32 {
+	use self::Strategy::*;
+	match strategy {
+		NakedSingles => 1,
+		HiddenSingles => 2,
+		LockedCandidates => 5,
+		NakedPairs | HiddenPairs => 8,
+		NakedTriples | HiddenTriples => 10,
+		NakedQuads | HiddenQuads => 12,
+		XWing => 15,
+		Swordfish => 20,
+		Jellyfish => 25,
+		SinglesChain => 30,
+	}
+}
+
+/// A coarse difficulty rating for a sudoku, derived from the strategies
+/// required to solve it. See [`Deductions::difficulty`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+	Beginner,
+	Easy,
+	Medium,
+	Hard,
+	Expert,
+}
+
+impl Difficulty {
+	fn from_cost(cost: u32) -> Self {
+		match cost {
+			0..=1 => Difficulty::Beginner,
+			2..=5 => Difficulty::Easy,
+			6..=12 => Difficulty::Medium,
+			13..=20 => Difficulty::Hard,
+			_ => Difficulty::Expert,
 		}
 	}
 }
@@ -96,7 +167,17 @@ pub enum Deduction<T> {
 		conflicts: T,
 	},
 
-    //SinglesChain(T),
+	/// Result of [`SinglesChain`](super::Strategy::SinglesChain)
+	SinglesChain {
+		digit: Digit,
+		/// The colored chain of conjugate (strong link) candidates, color A first,
+		/// then color B. `split` gives the number of color A candidates.
+		chain: T,
+		/// The number of color A candidates at the start of `chain`.
+		split: usize,
+		conflicts: T,
+	},
+
     #[doc(hidden)] __NonExhaustive
 }
 
@@ -116,7 +197,7 @@ impl Deduction<&'_ [Candidate]> {
 					_ => unreachable!(),
 				}
 			}
-			//SinglesChain { .. } => Strategy::SinglesChain,
+			SinglesChain { .. } => Strategy::SinglesChain,
 			Subsets { house, positions, conflicts, .. } => {
 				use crate::board::positions::HouseType::*;
 				let conflict_cell = conflicts[0].cell;
@@ -152,7 +233,7 @@ impl Deduction<&'_ [Candidate]> {
 impl _Deduction {
 	/// Replace the index ranges from the internal representation with slices
 	/// for the external API
-	fn with_slices(self, eliminated: &[Candidate]) -> Deduction<&[Candidate]> {
+	fn with_slices<'a>(self, eliminated: &'a [Candidate], chain_entries: &'a [Candidate]) -> Deduction<&'a [Candidate]> {
 		use self::Deduction::*;
 		match self {
 			NakedSingles(c) => NakedSingles(c),
@@ -175,8 +256,51 @@ impl _Deduction {
 			}
 			=> BasicFish { lines, positions, digit, conflicts: &eliminated[conflicts]},
 
-			//SinglesChain(x) => SinglesChain(&eliminated[x]),
+			SinglesChain { digit, chain, split, conflicts }
+			=> SinglesChain { digit, chain: &chain_entries[chain], split, conflicts: &eliminated[conflicts] },
+
 			__NonExhaustive => __NonExhaustive
 		}
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn empty_deductions_are_beginner_with_a_zero_score() {
+		let deductions = Deductions {
+			deductions: Vec::new(),
+			deduced_entries: Vec::new(),
+			eliminated_entries: Vec::new(),
+			chain_entries: Vec::new(),
+		};
+
+		assert_eq!(deductions.score(), 0);
+		assert_eq!(deductions.difficulty(), Difficulty::Beginner);
+	}
+
+	#[test]
+	fn dominant_strategy_wins_the_score_and_difficulty() {
+		// Mixes a nearly-free naked single (cost 1) with a singles chain (cost
+		// 30): the chain should dominate both the difficulty rating and the
+		// bulk of the score, with the naked single only nudging the total up.
+		let digit = Digit::new(0);
+		let naked = Deduction::NakedSingles(Candidate { cell: Cell::new(0), digit });
+		let chain_entries = vec![
+			Candidate { cell: Cell::new(1), digit },
+			Candidate { cell: Cell::new(2), digit },
+		];
+		let chain = Deduction::SinglesChain { digit, chain: 0..2, split: 1, conflicts: 0..0 };
+		let deductions = Deductions {
+			deductions: vec![naked, chain],
+			deduced_entries: Vec::new(),
+			eliminated_entries: Vec::new(),
+			chain_entries,
+		};
+
+		assert_eq!(deductions.difficulty(), Difficulty::Expert);
+		assert_eq!(deductions.score(), 30_000 + (1 + 30));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+//! Strategies used to solve sudokus the way a human would, by deduction
+//! rather than search.
+
+pub mod deduction;
+mod singles_chain;
+mod render;
+
+pub use self::singles_chain::find_singles_chains;
+pub use self::render::{CellRole, RenderedDeduction};
+
+/// The strategies that the solver can use to find deductions.
+///
+/// Strategies are ordered roughly by difficulty, cheapest first. See
+/// [`Deductions::difficulty`](self::deduction::Deductions::difficulty) for how the
+/// strategies used are turned into a difficulty rating; it uses a fixed cost per
+/// strategy, not this declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Strategy {
+	NakedSingles,
+	HiddenSingles,
+	LockedCandidates,
+	NakedPairs,
+	NakedTriples,
+	NakedQuads,
+	HiddenPairs,
+	HiddenTriples,
+	HiddenQuads,
+	XWing,
+	Swordfish,
+	Jellyfish,
+	/// Simple Coloring / Singles Chains: a chain of conjugate (strong link) pairs
+	/// for a single digit, 2-colored and checked for contradictions.
+	SinglesChain,
+}
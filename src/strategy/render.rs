@@ -0,0 +1,271 @@
+//! Renders a [`Deduction`] and the board it applies to as a highlighted grid,
+//! turning a [`Deductions::iter`](super::deduction::Deductions::iter) entry
+//! into something presentable to a person solving the puzzle.
+
+use crate::board::*;
+use crate::bitset::Set;
+use super::deduction::Deduction;
+
+/// The role a single cell plays in a rendered deduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellRole {
+	/// A clue that was part of the original puzzle.
+	Given(Digit),
+	/// Already solved, but not part of this deduction.
+	Solved(Digit),
+	/// A candidate this deduction reasons about (its defining set: the
+	/// positions, line or miniline the strategy name refers to). Holds every
+	/// digit of the defining set this cell is part of, since e.g. a naked
+	/// pair's cell is defining for both of its digits at once.
+	Defining(Set<Digit>),
+	/// A candidate in the defining set, marked with the second of two colors.
+	/// Only used for [`SinglesChain`](super::deduction::Deduction::SinglesChain),
+	/// to distinguish its two conjugate-link colors.
+	DefiningAlt(Digit),
+	/// A candidate this deduction eliminates.
+	Eliminated(Digit),
+	/// Not involved in this deduction.
+	Unaffected,
+}
+
+/// A board annotated with the [`CellRole`] every cell plays in one
+/// [`Deduction`], ready to be turned into text or handed to a GUI for custom
+/// styling.
+#[derive(Debug, Clone)]
+pub struct RenderedDeduction {
+	roles: Box<[CellRole; 81]>,
+}
+
+impl RenderedDeduction {
+	/// The role of `cell` in the deduction this was rendered from.
+	pub fn role(&self, cell: Cell) -> CellRole {
+		self.roles[cell.as_index()]
+	}
+
+	/// Renders the grid as a bordered, plain-Unicode table, suitable for a
+	/// terminal. Defining candidates are wrapped in `()` (the second chain
+	/// color in `<>`), eliminated ones in `[]`.
+	pub fn to_unicode_string(&self) -> String {
+		let mut out = String::new();
+		let horizontal = "───";
+		let thick_horizontal = "━━━";
+		for block_row in 0..3 {
+			if block_row == 0 {
+				out.push_str(&border(thick_horizontal, '┏', '┳', '┓'));
+			} else {
+				out.push_str(&border(horizontal, '┣', '╋', '┫'));
+			}
+			for row in block_row * 3..block_row * 3 + 3 {
+				out.push('┃');
+				for block_col in 0..3 {
+					for col in block_col * 3..block_col * 3 + 3 {
+						let cell = Cell::new(row * 9 + col);
+						out.push_str(&cell_text(self.role(cell)));
+						out.push(if col % 3 == 2 { '┃' } else { '│' });
+					}
+				}
+				out.push('\n');
+			}
+		}
+		out.push_str(&border(thick_horizontal, '┗', '┻', '┛'));
+		out
+	}
+}
+
+fn border(segment: &str, left: char, mid: char, right: char) -> String {
+	let mut line = String::new();
+	line.push(left);
+	for block in 0..3 {
+		for col in 0..3 {
+			line.push_str(segment);
+			line.push(if col == 2 { mid } else { '─' });
+		}
+		if block == 2 {
+			line.pop();
+			line.push(right);
+		}
+	}
+	line.push('\n');
+	line
+}
+
+fn cell_text(role: CellRole) -> String {
+	match role {
+		CellRole::Given(digit) => format!(" {} ", digit.as_index() + 1),
+		CellRole::Solved(digit) => format!(" {} ", digit.as_index() + 1),
+		CellRole::Defining(digits) => format!("({})", digits_text(digits)),
+		CellRole::DefiningAlt(digit) => format!("<{}>", digit.as_index() + 1),
+		CellRole::Eliminated(digit) => format!("[{}]", digit.as_index() + 1),
+		CellRole::Unaffected => "   ".to_owned(),
+	}
+}
+
+fn digits_text(digits: Set<Digit>) -> String {
+	digits.into_iter().map(|digit| (digit.as_index() + 1).to_string()).collect()
+}
+
+impl Deduction<&'_ [Candidate]> {
+	/// Renders this deduction against `grid` (the board's current state,
+	/// givens and already-deduced fills alike), marking the defining
+	/// candidates (the positions/lines/miniline the strategy's name refers
+	/// to) and the eliminated candidates (`conflicts`) with distinct roles.
+	///
+	/// `givens` distinguishes the original clues from cells later filled in
+	/// by the solver, so the two can be styled differently.
+	pub fn render(&self, givens: &[Option<Digit>; 81], grid: &[Option<Digit>; 81]) -> RenderedDeduction {
+		let mut roles = Box::new([CellRole::Unaffected; 81]);
+		for cell in Cell::all() {
+			match (givens[cell.as_index()], grid[cell.as_index()]) {
+				(Some(digit), _) => roles[cell.as_index()] = CellRole::Given(digit),
+				(None, Some(digit)) => roles[cell.as_index()] = CellRole::Solved(digit),
+				(None, None) => {}
+			}
+		}
+
+		use self::Deduction::*;
+		match *self {
+			NakedSingles(candidate) => {
+				mark(&mut roles, grid, candidate.cell, Set::from(candidate.digit), CellRole::Defining)
+			}
+			HiddenSingles(candidate, _house_type) => {
+				mark(&mut roles, grid, candidate.cell, Set::from(candidate.digit), CellRole::Defining)
+			}
+			LockedCandidates { digit, miniline, conflicts, .. } => {
+				for cell in miniline.cells() {
+					mark(&mut roles, grid, cell, Set::from(digit), CellRole::Defining);
+				}
+				mark_conflicts(&mut roles, grid, conflicts);
+			}
+			Subsets { positions, digits, conflicts, house } => {
+				// Every cell in the locked set is defining for the whole set
+				// of digits, not just one of them.
+				for position in positions {
+					mark(&mut roles, grid, position.cell_in(house), digits, CellRole::Defining);
+				}
+				mark_conflicts(&mut roles, grid, conflicts);
+			}
+			BasicFish { digit, lines, positions, conflicts } => {
+				for line in lines {
+					for position in positions {
+						mark(&mut roles, grid, position.cell_in(line), Set::from(digit), CellRole::Defining);
+					}
+				}
+				mark_conflicts(&mut roles, grid, conflicts);
+			}
+			SinglesChain { digit, chain, split, conflicts } => {
+				for candidate in &chain[..split] {
+					mark(&mut roles, grid, candidate.cell, Set::from(digit), CellRole::Defining);
+				}
+				for candidate in &chain[split..] {
+					mark(&mut roles, grid, candidate.cell, digit, CellRole::DefiningAlt);
+				}
+				mark_conflicts(&mut roles, grid, conflicts);
+			}
+			__NonExhaustive => unreachable!(),
+		}
+
+		RenderedDeduction { roles }
+	}
+}
+
+fn mark<T>(
+	roles: &mut [CellRole; 81],
+	grid: &[Option<Digit>; 81],
+	cell: Cell,
+	payload: T,
+	as_role: impl Fn(T) -> CellRole,
+) {
+	if grid[cell.as_index()].is_none() {
+		roles[cell.as_index()] = as_role(payload);
+	}
+}
+
+fn mark_conflicts(roles: &mut [CellRole; 81], grid: &[Option<Digit>; 81], conflicts: &[Candidate]) {
+	for candidate in conflicts {
+		if grid[candidate.cell.as_index()].is_none() {
+			roles[candidate.cell.as_index()] = CellRole::Eliminated(candidate.digit);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EMPTY: [Option<Digit>; 81] = [None; 81];
+
+	#[test]
+	fn given_and_solved_cells_keep_their_digit_unaffected_elsewhere() {
+		let digit = Digit::new(3);
+		let mut givens = EMPTY;
+		givens[0] = Some(digit);
+		let mut grid = givens;
+		grid[1] = Some(digit);
+
+		let deduction = Deduction::NakedSingles(Candidate { cell: Cell::new(4), digit });
+		let rendered = deduction.render(&givens, &grid);
+
+		assert_eq!(rendered.role(Cell::new(0)), CellRole::Given(digit));
+		assert_eq!(rendered.role(Cell::new(1)), CellRole::Solved(digit));
+		assert_eq!(rendered.role(Cell::new(2)), CellRole::Unaffected);
+	}
+
+	#[test]
+	fn naked_single_marks_its_cell_as_defining() {
+		let digit = Digit::new(5);
+		let candidate = Candidate { cell: Cell::new(10), digit };
+		let deduction = Deduction::NakedSingles(candidate);
+
+		let rendered = deduction.render(&EMPTY, &EMPTY);
+
+		assert_eq!(rendered.role(candidate.cell), CellRole::Defining(Set::from(digit)));
+	}
+
+	#[test]
+	fn subsets_marks_every_position_with_the_whole_digit_set() {
+		// Regression test for the bug fixed in 2e1387f, where each position of a
+		// locked set was marked once per digit, leaving only the last digit's
+		// role in place instead of the whole set.
+		use crate::board::positions::HouseType::Row;
+
+		let house = House::all().find(|house| matches!(house.categorize(), Row(_))).unwrap();
+		let cells: Vec<Cell> = house.cells().take(2).collect();
+		let mut positions = Set::from(cells[0].row_pos());
+		positions.insert(cells[1].row_pos());
+		let mut digits = Set::from(Digit::new(2));
+		digits.insert(Digit::new(4));
+
+		let deduction = Deduction::Subsets { house, positions, digits, conflicts: &[] };
+		let rendered = deduction.render(&EMPTY, &EMPTY);
+
+		assert_eq!(rendered.role(cells[0]), CellRole::Defining(digits));
+		assert_eq!(rendered.role(cells[1]), CellRole::Defining(digits));
+	}
+
+	#[test]
+	fn singles_chain_splits_defining_and_defining_alt_and_marks_conflicts() {
+		let digit = Digit::new(6);
+		let a = Candidate { cell: Cell::new(0), digit };
+		let b = Candidate { cell: Cell::new(1), digit };
+		let conflict = Candidate { cell: Cell::new(2), digit };
+		let chain = [a, b];
+
+		let deduction =
+			Deduction::SinglesChain { digit, chain: &chain[..], split: 1, conflicts: &[conflict] };
+		let rendered = deduction.render(&EMPTY, &EMPTY);
+
+		assert_eq!(rendered.role(a.cell), CellRole::Defining(Set::from(digit)));
+		assert_eq!(rendered.role(b.cell), CellRole::DefiningAlt(digit));
+		assert_eq!(rendered.role(conflict.cell), CellRole::Eliminated(digit));
+	}
+
+	#[test]
+	fn to_unicode_string_renders_a_81_cell_bordered_grid() {
+		let deduction = Deduction::NakedSingles(Candidate { cell: Cell::new(0), digit: Digit::new(0) });
+		let rendered = deduction.render(&EMPTY, &EMPTY);
+		let text = rendered.to_unicode_string();
+
+		assert_eq!(text.lines().count(), 13); // 4 borders + 9 rows
+		assert!(text.contains("(1)"));
+	}
+}
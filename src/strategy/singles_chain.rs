@@ -0,0 +1,202 @@
+//! Simple Coloring (Singles Chains): build the graph of conjugate pairs for a
+//! digit and 2-color each connected component to find eliminations.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::board::*;
+use crate::bitset::Set;
+use super::deduction::Deductions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Color {
+	A,
+	B,
+}
+
+impl Color {
+	fn flip(self) -> Self {
+		match self {
+			Color::A => Color::B,
+			Color::B => Color::A,
+		}
+	}
+}
+
+/// Finds all Singles Chain deductions for `digit` and records them in `deductions`.
+///
+/// `candidates` holds the current candidate set for every cell. A conjugate pair
+/// is a house in which `digit` is a candidate in exactly two cells; these pairs
+/// are the edges of the coloring graph. Components with a single cell carry no
+/// information and are skipped.
+pub fn find_singles_chains(
+	candidates: &[Set<Digit>; 81],
+	digit: Digit,
+	deductions: &mut Deductions,
+) {
+	let mut adjacency: HashMap<Cell, Vec<Cell>> = HashMap::new();
+	for house in House::all() {
+		let cells_with_digit: Vec<Cell> = house
+			.cells()
+			.filter(|&cell| candidates[cell.as_index()].contains(digit))
+			.collect();
+		if let [a, b] = cells_with_digit[..] {
+			adjacency.entry(a).or_default().push(b);
+			adjacency.entry(b).or_default().push(a);
+		}
+	}
+
+	let nodes: Vec<Cell> = adjacency.keys().copied().collect();
+	let mut colors: HashMap<Cell, Color> = HashMap::new();
+	for &start in &nodes {
+		if colors.contains_key(&start) {
+			continue;
+		}
+
+		let mut component = vec![start];
+		let mut queue = VecDeque::new();
+		queue.push_back(start);
+		colors.insert(start, Color::A);
+		while let Some(cell) = queue.pop_front() {
+			let this_color = colors[&cell];
+			for &neighbor in &adjacency[&cell] {
+				if colors.contains_key(&neighbor) {
+					continue;
+				}
+				colors.insert(neighbor, this_color.flip());
+				component.push(neighbor);
+				queue.push_back(neighbor);
+			}
+		}
+
+		if component.len() > 1 {
+			record_component(candidates, digit, &component, &colors, deductions);
+		}
+	}
+}
+
+/// Applies the color wrap and color trap rules to a single colored component.
+fn record_component(
+	candidates: &[Set<Digit>; 81],
+	digit: Digit,
+	component: &[Cell],
+	colors: &HashMap<Cell, Color>,
+	deductions: &mut Deductions,
+) {
+	let wrapped_color = component.iter().enumerate().find_map(|(i, &a)| {
+		component[i + 1..]
+			.iter()
+			.find(|&&b| colors[&a] == colors[&b] && a.sees(b))
+			.map(|_| colors[&a])
+	});
+
+	let conflicts_start = deductions.eliminated_entries.len();
+	if let Some(color) = wrapped_color {
+		for &cell in component {
+			if colors[&cell] == color {
+				deductions.eliminated_entries.push(Candidate { cell, digit });
+			}
+		}
+	} else {
+		for cell in Cell::all() {
+			if component.contains(&cell) || !candidates[cell.as_index()].contains(digit) {
+				continue;
+			}
+			let sees_a = component.iter().any(|&c| colors[&c] == Color::A && cell.sees(c));
+			let sees_b = component.iter().any(|&c| colors[&c] == Color::B && cell.sees(c));
+			if sees_a && sees_b {
+				deductions.eliminated_entries.push(Candidate { cell, digit });
+			}
+		}
+	}
+	let conflicts_end = deductions.eliminated_entries.len();
+	if conflicts_end == conflicts_start {
+		return;
+	}
+
+	// Only cells whose candidacy was *not* disproven belong in the chain: in the
+	// color-wrap case that's every cell except the wrapped (now eliminated)
+	// color, in the color-trap case it's the whole component.
+	let color_a = component.iter().copied().filter(|c| colors[c] == Color::A);
+	let color_b = component.iter().copied().filter(|c| colors[c] == Color::B);
+	let (chain_a, chain_b): (Vec<Cell>, Vec<Cell>) = match wrapped_color {
+		Some(Color::A) => (Vec::new(), color_b.collect()),
+		Some(Color::B) => (color_a.collect(), Vec::new()),
+		None => (color_a.collect(), color_b.collect()),
+	};
+
+	let chain_start = deductions.chain_entries.len();
+	let split = chain_a.len();
+	for cell in chain_a.into_iter().chain(chain_b) {
+		deductions.chain_entries.push(Candidate { cell, digit });
+	}
+	let chain_end = deductions.chain_entries.len();
+
+	deductions.deductions.push(super::deduction::Deduction::SinglesChain {
+		digit,
+		chain: chain_start..chain_end,
+		split,
+		conflicts: conflicts_start..conflicts_end,
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::deduction::Deduction;
+
+	fn empty_deductions() -> Deductions {
+		Deductions {
+			deductions: Vec::new(),
+			deduced_entries: Vec::new(),
+			eliminated_entries: Vec::new(),
+			chain_entries: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn color_trap_eliminates_a_candidate_seeing_both_colors() {
+		// Chain: r0c0 --(row 0)-- r0c1 --(col 1)-- r3c1, all the only cells
+		// with `digit` in their respective house. r1c2 shares block 0 with
+		// both r0c0 and r0c1, so it sees both chain colors and gets trapped.
+		let digit = Digit::new(4);
+		let mut candidates = [Set::from(Digit::new(0)); 81];
+		candidates[0] = Set::from(digit); // r0c0
+		candidates[1] = Set::from(digit); // r0c1
+		candidates[28] = Set::from(digit); // r3c1
+		candidates[11] = Set::from(digit); // r1c2
+
+		let mut deductions = empty_deductions();
+		find_singles_chains(&candidates, digit, &mut deductions);
+
+		assert_eq!(deductions.len(), 1);
+		match deductions.get(0).unwrap() {
+			Deduction::SinglesChain { digit: found_digit, chain, split, conflicts } => {
+				assert_eq!(found_digit, digit);
+				assert_eq!(conflicts, &[Candidate { cell: Cell::new(11), digit }]);
+				assert_eq!(chain.len(), 3);
+
+				// Colors are an arbitrary labeling of the chain's single
+				// bipartition, so only check the two groups it produces,
+				// not which one happens to be called "A".
+				let (group_a, group_b) = chain.split_at(split);
+				let (singleton, pair) = if group_a.len() == 1 { (group_a, group_b) } else { (group_b, group_a) };
+				assert_eq!(singleton, &[Candidate { cell: Cell::new(1), digit }]);
+				let mut pair: Vec<Cell> = pair.iter().map(|c| c.cell).collect();
+				pair.sort_by_key(|cell| cell.as_index());
+				assert_eq!(pair, vec![Cell::new(0), Cell::new(28)]);
+			}
+			other => panic!("expected a SinglesChain deduction, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn no_conjugate_pairs_means_no_deduction() {
+		// `digit` has no candidates anywhere, so there are no conjugate pairs
+		// and no component ever reaches the minimum size of two cells.
+		let digit = Digit::new(0);
+		let candidates = [Set::from(Digit::new(1)); 81];
+		let mut deductions = empty_deductions();
+		find_singles_chains(&candidates, digit, &mut deductions);
+		assert_eq!(deductions.len(), 0);
+	}
+}